@@ -8,9 +8,26 @@ export const THIRD_PLACE_PCT = 20n
 export const BASE_POINTS = 100
 export const TIME_BONUS_MULTIPLIER = 10.5
 
+// Extra time allowed past a question's time limit for network latency
+export const ANSWER_GRACE_PERIOD_MS = 5000
+
+// Question media constraints
+export const MAX_QUESTION_MEDIA = 4
+export const MAX_QUESTION_MEDIA_SIZE_BYTES = 25 * 1024 * 1024
+export const ALLOWED_QUESTION_MEDIA_TYPES = [
+  'image/png',
+  'image/jpeg',
+  'image/gif',
+  'image/webp',
+  'audio/mpeg',
+  'audio/ogg',
+  'audio/wav'
+]
+
 // Game status constants
 export const GAME_STATUS = {
   WAITING: 'waiting',
+  STARTING: 'starting',
   IN_PROGRESS: 'in_progress',
   COMPLETED: 'completed'
 } as const
@@ -1,3 +1,10 @@
+export interface QuestionMedia {
+  uri: string
+  content_hash: string // sha256 hex digest of the asset
+  mime_type: string
+  size_bytes: number
+}
+
 export interface CreateQuizRequest {
   title: string
   description?: string
@@ -6,6 +13,7 @@ export interface CreateQuizRequest {
     options: string[]
     correct_answer_index: number
     time_limit?: number
+    media?: QuestionMedia[]
   }>
   prize_amount: number
   prize_token?: string
@@ -38,6 +46,8 @@ export interface CompleteGameRequest {
 
 export interface Question {
   id: string
+  quiz_id: string
+  order_index: number
   correct_answer_index: number
   time_limit: number
 }
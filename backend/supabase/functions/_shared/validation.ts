@@ -1,3 +1,10 @@
+import {
+  MAX_QUESTION_MEDIA,
+  MAX_QUESTION_MEDIA_SIZE_BYTES,
+  ALLOWED_QUESTION_MEDIA_TYPES
+} from './constants.ts'
+import type { QuestionMedia } from './types.ts'
+
 export function validateRequired(fields: Record<string, unknown>): string | null {
   for (const [key, value] of Object.entries(fields)) {
     if (value === undefined || value === null || value === '') {
@@ -20,3 +27,28 @@ export function compareAddresses(addr1?: string | null, addr2?: string | null):
   return addr1.toLowerCase() === addr2.toLowerCase()
 }
 
+export function validateQuestionMedia(media?: QuestionMedia[]): string | null {
+  if (media === undefined || media === null) return null
+  if (!Array.isArray(media)) return 'media must be an array'
+  if (media.length > MAX_QUESTION_MEDIA) {
+    return `at most ${MAX_QUESTION_MEDIA} media attachments are allowed`
+  }
+
+  for (let i = 0; i < media.length; i++) {
+    const m = media[i]
+    if (!m?.uri || !/^(ipfs|https):\/\/\S+$/.test(m.uri)) {
+      return `media ${i + 1}: uri must be an ipfs:// or https:// URL`
+    }
+    if (!m.content_hash || !/^[a-fA-F0-9]{64}$/.test(m.content_hash)) {
+      return `media ${i + 1}: content_hash must be a sha256 hex digest`
+    }
+    if (!ALLOWED_QUESTION_MEDIA_TYPES.includes(m.mime_type)) {
+      return `media ${i + 1}: unsupported mime_type ${m.mime_type}`
+    }
+    if (!Number.isInteger(m.size_bytes) || m.size_bytes <= 0 || m.size_bytes > MAX_QUESTION_MEDIA_SIZE_BYTES) {
+      return `media ${i + 1}: size_bytes must be between 1 and ${MAX_QUESTION_MEDIA_SIZE_BYTES}`
+    }
+  }
+
+  return null
+}
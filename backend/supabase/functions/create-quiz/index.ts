@@ -1,7 +1,7 @@
 import { serve } from "https://deno.land/std@0.168.0/http/server.ts"
 import { handleCorsPreFlight } from '../_shared/cors.ts'
 import { successResponse, errorResponse } from '../_shared/response.ts'
-import { validateRequired } from '../_shared/validation.ts'
+import { validateRequired, validateQuestionMedia } from '../_shared/validation.ts'
 import { initSupabaseClient } from '../_shared/supabase.ts'
 import { QUIZ_STATUS } from '../_shared/constants.ts'
 import type { CreateQuizRequest } from '../_shared/types.ts'
@@ -36,6 +36,11 @@ function validateQuizData(request: CreateQuizRequest): string | null {
     if (q.correct_answer_index < 0 || q.correct_answer_index >= q.options.length) {
       return `Question ${i + 1}: correct_answer_index must be between 0 and ${q.options.length - 1}`
     }
+
+    const mediaError = validateQuestionMedia(q.media)
+    if (mediaError) {
+      return `Question ${i + 1}: ${mediaError}`
+    }
   }
   
   if (request.scheduled_start_time) {
@@ -125,7 +130,14 @@ async function createQuestions(supabase: ReturnType<typeof initSupabaseClient>,
     options: q.options,
     correct_answer_index: q.correct_answer_index,
     order_index: index + 1,
-    time_limit: q.time_limit || 15
+    time_limit: q.time_limit || 15,
+    // Store only the validated fields, not whatever else the client sent
+    media: (q.media || []).map(({ uri, content_hash, mime_type, size_bytes }) => ({
+      uri,
+      content_hash,
+      mime_type,
+      size_bytes
+    }))
   }))
 
   const { error: questionsError } = await supabase
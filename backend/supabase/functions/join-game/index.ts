@@ -33,6 +33,7 @@ async function fetchGameSession(supabase, roomCode) {
         prize_amount,
         prize_token,
         creator_address,
+        user_id,
         status
       )
     `
@@ -129,6 +130,13 @@ async function fetchAllPlayers(supabase, gameSessionId) {
 function isCreator(creatorAddress, playerAddress) {
   return compareAddresses(creatorAddress, playerAddress);
 }
+// The host session (creator_session_id) is the only session the creator may
+// hold in a game. Any other join by the creator's wallet or auth user would be
+// a player session for someone who has seen the answers.
+function isCreatorOwnedJoin(quiz, walletAddress, userId) {
+  if (quiz?.user_id && userId && quiz.user_id === userId) return true;
+  return isCreator(quiz?.creator_address, walletAddress);
+}
 serve(async (req) => {
   if (req.method === "OPTIONS") {
     return handleCorsPreFlight();
@@ -261,6 +269,15 @@ serve(async (req) => {
     if (sessionError) {
       return errorResponse(sessionError, 400);
     }
+    // Reject a second creator-owned session once the host session exists
+    if (
+      gameSession.creator_session_id &&
+      isCreatorOwnedJoin(gameSession.quizzes, wallet_address, userId)
+    ) {
+      return errorResponse("Creator cannot play", 403, {
+        code: "CREATOR_CANNOT_PLAY",
+      });
+    }
     // Create new player session
     const playerSession = await createPlayerSession(
       supabase,
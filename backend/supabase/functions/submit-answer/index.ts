@@ -1,15 +1,15 @@
 import { serve } from "https://deno.land/std@0.168.0/http/server.ts"
 import { handleCorsPreFlight } from '../_shared/cors.ts'
 import { successResponse, errorResponse } from '../_shared/response.ts'
-import { validateRequired } from '../_shared/validation.ts'
+import { compareAddresses } from '../_shared/validation.ts'
 import { initSupabaseClient } from '../_shared/supabase.ts'
-import { BASE_POINTS, TIME_BONUS_MULTIPLIER } from '../_shared/constants.ts'
+import { BASE_POINTS, TIME_BONUS_MULTIPLIER, ANSWER_GRACE_PERIOD_MS, GAME_STATUS } from '../_shared/constants.ts'
 import type { SubmitAnswerRequest, Question } from '../_shared/types.ts'
 
 async function fetchQuestion(supabase: ReturnType<typeof initSupabaseClient>, questionId: string): Promise<Question | null> {
   const { data, error } = await supabase
     .from('questions')
-    .select('correct_answer_index, time_limit')
+    .select('id, quiz_id, order_index, correct_answer_index, time_limit')
     .eq('id', questionId)
     .single()
 
@@ -17,6 +17,102 @@ async function fetchQuestion(supabase: ReturnType<typeof initSupabaseClient>, qu
   return data
 }
 
+type PlayerSessionWithGame = {
+  id: string
+  wallet_address: string | null
+  user_id: string | null
+  game_sessions: {
+    quiz_id: string
+    status: string
+    current_question_index: number
+    question_started_at: string | null
+    creator_session_id: string | null
+    quizzes: { creator_address: string; user_id: string | null } | null
+  } | null
+}
+
+async function fetchPlayerSession(supabase: ReturnType<typeof initSupabaseClient>, playerSessionId: string): Promise<PlayerSessionWithGame | null> {
+  const { data, error } = await supabase
+    .from('player_sessions')
+    .select(`
+      id,
+      wallet_address,
+      user_id,
+      game_sessions (
+        quiz_id,
+        status,
+        current_question_index,
+        question_started_at,
+        creator_session_id,
+        quizzes (
+          creator_address,
+          user_id
+        )
+      )
+    `)
+    .eq('id', playerSessionId)
+    .single()
+
+  if (error) return null
+  return data
+}
+
+// The creator has seen the correct answers, so their session must never score.
+// This covers the host session, any session using the creator's wallet, and
+// any session of the creator's auth user (which groups all their wallets)
+function isCreatorSession(playerSession: PlayerSessionWithGame): boolean {
+  const gameSession = playerSession.game_sessions
+  if (gameSession?.creator_session_id === playerSession.id) {
+    return true
+  }
+  const creatorUserId = gameSession?.quizzes?.user_id
+  if (creatorUserId && playerSession.user_id === creatorUserId) {
+    return true
+  }
+  return compareAddresses(gameSession?.quizzes?.creator_address, playerSession.wallet_address)
+}
+
+type SubmissionError = { message: string; status: number; code: string }
+
+// question_started_at is stamped by the database clock (see the
+// stamp_question_started_at trigger), so this is comparable with Date.now()
+function measureElapsedMs(gameSession: PlayerSessionWithGame['game_sessions'], nowMs: number): number | null {
+  if (!gameSession?.question_started_at) return null
+  return Math.max(0, nowMs - new Date(gameSession.question_started_at).getTime())
+}
+
+// Answers are only accepted for the question the host is currently showing,
+// within its time limit plus a grace period. One answer per player and
+// question is enforced by the unique_player_question_answer constraint, which
+// is why no separate attempt nonce is needed
+function validateQuestionWindow(
+  gameSession: PlayerSessionWithGame['game_sessions'],
+  question: Question,
+  elapsedMs: number | null
+): SubmissionError | null {
+  if (!gameSession || gameSession.quiz_id !== question.quiz_id) {
+    return { message: 'Question does not belong to this game', status: 400, code: 'QUESTION_NOT_IN_GAME' }
+  }
+
+  // Players can reach question 1 while the game is still 'starting': only the
+  // creator's play page moves it to 'in_progress', and scheduled quizzes stay
+  // 'starting' until then
+  const isPlayable =
+    gameSession.status === GAME_STATUS.IN_PROGRESS ||
+    (gameSession.status === GAME_STATUS.STARTING && gameSession.current_question_index === 0)
+
+  // order_index is 1-based while current_question_index is 0-based
+  if (!isPlayable || question.order_index !== gameSession.current_question_index + 1) {
+    return { message: 'Question is not active', status: 409, code: 'QUESTION_NOT_ACTIVE' }
+  }
+
+  if (elapsedMs !== null && elapsedMs > question.time_limit * 1000 + ANSWER_GRACE_PERIOD_MS) {
+    return { message: 'Answer submitted too late', status: 400, code: 'ANSWER_WINDOW_CLOSED' }
+  }
+
+  return null
+}
+
 function validateAnswerSubmission(timeTakenMs: number, timeLimitSeconds: number): string | null {
   const timeLimitMs = timeLimitSeconds * 1000
   
@@ -39,6 +135,8 @@ function calculatePoints(isCorrect: boolean, timeTakenMs: number, timeLimitSecon
   return Math.floor(BASE_POINTS + timeBonus)
 }
 
+const UNIQUE_VIOLATION = '23505'
+
 async function createAnswer(
   supabase: ReturnType<typeof initSupabaseClient>,
   playerSessionId: string,
@@ -70,6 +168,12 @@ async function createAnswer(
     .select()
     .single()
 
+  // A concurrent submission won the unique (player_session_id, question_id) race
+  if (error?.code === UNIQUE_VIOLATION) {
+    console.log('Duplicate answer rejected by constraint:', { playerSessionId, questionId })
+    return null
+  }
+
   if (error) {
     console.error('Error creating answer record:', {
       error: error.message,
@@ -150,16 +254,39 @@ serve(async (req) => {
       return errorResponse('Missing or invalid required fields', 400)
     }
 
+    // Fetch player session and reject the quiz creator
+    const playerSession = await fetchPlayerSession(supabase, player_session_id)
+    if (!playerSession) {
+      return errorResponse('Player session not found', 404)
+    }
+
+    if (isCreatorSession(playerSession)) {
+      return errorResponse('Creator cannot play', 403, { code: 'CREATOR_CANNOT_PLAY' })
+    }
+
     // Fetch question
     const question = await fetchQuestion(supabase, question_id)
     if (!question) {
       return errorResponse('Question not found', 404)
     }
 
+    // Reject submissions outside the active question's window
+    const elapsedMs = measureElapsedMs(playerSession.game_sessions, Date.now())
+    const windowError = validateQuestionWindow(playerSession.game_sessions, question, elapsedMs)
+    if (windowError) {
+      return errorResponse(windowError.message, windowError.status, { code: windowError.code })
+    }
+
+    // The client-reported time can't be shorter than the server-measured time,
+    // minus the grace period allowed for network latency
+    const timeTaken = elapsedMs === null
+      ? time_taken
+      : Math.max(time_taken, elapsedMs - ANSWER_GRACE_PERIOD_MS)
+
     // Validate submission timing
-    const timingError = validateAnswerSubmission(time_taken, question.time_limit)
+    const timingError = validateAnswerSubmission(timeTaken, question.time_limit)
     if (timingError) {
-      return errorResponse(timingError, 400)
+      return errorResponse(timingError, 400, { code: 'ANSWER_WINDOW_CLOSED' })
     }
 
     // Check if answer is correct (skip for timeout cases where answer_index is -1)
@@ -179,11 +306,11 @@ serve(async (req) => {
 
     if (existingAnswer) {
       console.log('Answer already exists for this question:', existingAnswer.id)
-      return errorResponse('Answer already submitted for this question', 400)
+      return errorResponse('Answer already submitted for this question', 409, { code: 'ANSWER_ALREADY_SUBMITTED' })
     }
 
     // Calculate points
-    const pointsEarned = calculatePoints(isCorrect, time_taken, question.time_limit)
+    const pointsEarned = calculatePoints(isCorrect, timeTaken, question.time_limit)
 
     // Create answer record
     const answer = await createAnswer(
@@ -192,10 +319,14 @@ serve(async (req) => {
       question_id,
       answer_index,
       isCorrect,
-      time_taken,
+      timeTaken,
       pointsEarned
     )
 
+    if (!answer) {
+      return errorResponse('Answer already submitted for this question', 409, { code: 'ANSWER_ALREADY_SUBMITTED' })
+    }
+
     // Update player's total score
     const newTotalScore = await updatePlayerScore(supabase, player_session_id, pointsEarned)
 
@@ -0,0 +1,9 @@
+-- Add optional media references to questions
+-- Each entry points at an off-chain asset (IPFS or HTTPS) and carries its
+-- sha256 content hash, MIME type and size so clients can verify integrity
+
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS media JSONB NOT NULL DEFAULT '[]'::jsonb;
+
+COMMENT ON COLUMN questions.media IS
+'Array of { uri, content_hash, mime_type, size_bytes } media references for the question';
@@ -0,0 +1,36 @@
+-- Stamp question_started_at with the database clock
+-- The host's browser writes question_started_at when it advances a question,
+-- but submit-answer compares it against the server clock to enforce the answer
+-- window. Overwrite the client value with NOW() so host clock skew can't shrink
+-- or extend the window.
+
+CREATE OR REPLACE FUNCTION stamp_question_started_at()
+RETURNS trigger
+LANGUAGE plpgsql
+SET search_path = public, pg_temp
+AS $$
+BEGIN
+  IF TG_OP = 'INSERT' THEN
+    IF NEW.question_started_at IS NOT NULL THEN
+      NEW.question_started_at := NOW();
+    END IF;
+    RETURN NEW;
+  END IF;
+
+  IF (NEW.question_started_at IS NOT NULL
+      AND NEW.question_started_at IS DISTINCT FROM OLD.question_started_at)
+     OR (NEW.status = 'in_progress'
+      AND NEW.current_question_index IS DISTINCT FROM OLD.current_question_index) THEN
+    NEW.question_started_at := NOW();
+  END IF;
+
+  RETURN NEW;
+END;
+$$;
+
+DROP TRIGGER IF EXISTS stamp_question_started_at ON game_sessions;
+
+CREATE TRIGGER stamp_question_started_at
+BEFORE INSERT OR UPDATE ON game_sessions
+FOR EACH ROW
+EXECUTE FUNCTION stamp_question_started_at();
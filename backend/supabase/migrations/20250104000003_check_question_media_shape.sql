@@ -0,0 +1,7 @@
+-- Ensure questions.media is always a JSON array
+-- create-quiz validates each entry, but rows written outside the edge function
+-- must not break the array shape the frontend expects
+
+ALTER TABLE questions
+ADD CONSTRAINT questions_media_is_array
+CHECK (jsonb_typeof(media) = 'array');
@@ -29,6 +29,14 @@ export interface Question {
   correct_answer_index: number
   order_index: number
   time_limit: number
+  media?: QuestionMedia[]
+}
+
+export interface QuestionMedia {
+  uri: string
+  content_hash: string
+  mime_type: string
+  size_bytes: number
 }
 
 export interface GameSession {
@@ -75,6 +83,7 @@ export interface CreateQuizRequest {
     options: string[]
     correct_answer_index: number
     time_limit?: number
+    media?: QuestionMedia[]
   }>
   prize_amount: number
   prize_token?: string | null